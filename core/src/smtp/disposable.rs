@@ -0,0 +1,87 @@
+// check-if-email-exists
+// Copyright (C) 2018-2022 Reacher
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published
+// by the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use crate::util::input_output::CheckEmailInput;
+
+/// Bundled list of well-known disposable / throwaway email providers, one
+/// registrable domain per line. Lines starting with `#` are comments.
+const BUNDLED_DISPOSABLE_DOMAINS: &str = include_str!("disposable_domains.txt");
+
+/// Lazily-parsed set of the bundled disposable domains, built once at first use
+/// and shared across all checks.
+fn bundled_domains() -> &'static HashSet<String> {
+	static DOMAINS: OnceLock<HashSet<String>> = OnceLock::new();
+	DOMAINS.get_or_init(|| {
+		BUNDLED_DISPOSABLE_DOMAINS
+			.lines()
+			.map(str::trim)
+			.filter(|line| !line.is_empty() && !line.starts_with('#'))
+			.map(str::to_lowercase)
+			.collect()
+	})
+}
+
+/// Returns `true` if `domain` is a known disposable email provider. Matching is
+/// case-insensitive and done on the registrable domain: a subdomain host such
+/// as `mail.mailinator.com` still matches the listed `mailinator.com`. The
+/// bundled list can be extended per-check — without recompiling — via
+/// [`CheckEmailInput::extra_disposable_domains`].
+pub fn is_disposable(domain: &str, input: &CheckEmailInput) -> bool {
+	let domain = domain.trim().to_lowercase();
+
+	super::domain_suffixes(&domain).any(|suffix| {
+		bundled_domains().contains(suffix)
+			|| input
+				.extra_disposable_domains
+				.iter()
+				.any(|d| d.trim().to_lowercase() == suffix)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::is_disposable;
+	use crate::util::input_output::CheckEmailInput;
+
+	#[test]
+	fn detects_bundled_disposable_domain() {
+		let input = CheckEmailInput::default();
+		assert!(is_disposable("mailinator.com", &input));
+	}
+
+	#[test]
+	fn matches_on_registrable_domain_of_subdomain() {
+		let input = CheckEmailInput::default();
+		assert!(is_disposable("mail.mailinator.com", &input));
+	}
+
+	#[test]
+	fn leaves_regular_domain_untouched() {
+		let input = CheckEmailInput::default();
+		assert!(!is_disposable("gmail.com", &input));
+	}
+
+	#[test]
+	fn honours_extra_domains_from_input() {
+		let mut input = CheckEmailInput::default();
+		input.set_extra_disposable_domains(vec!["example.com".into()]);
+		assert!(is_disposable("example.com", &input));
+		assert!(is_disposable("mx.example.com", &input));
+	}
+}
@@ -0,0 +1,87 @@
+// check-if-email-exists
+// Copyright (C) 2018-2022 Reacher
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published
+// by the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use crate::util::input_output::CheckEmailInput;
+
+/// Bundled list of free consumer mailbox providers, one registrable domain per
+/// line. Lines starting with `#` are comments.
+const BUNDLED_FREE_DOMAINS: &str = include_str!("free_domains.txt");
+
+/// Lazily-parsed set of the bundled free providers, built once at first use and
+/// shared across all checks.
+fn bundled_domains() -> &'static HashSet<String> {
+	static DOMAINS: OnceLock<HashSet<String>> = OnceLock::new();
+	DOMAINS.get_or_init(|| {
+		BUNDLED_FREE_DOMAINS
+			.lines()
+			.map(str::trim)
+			.filter(|line| !line.is_empty() && !line.starts_with('#'))
+			.map(str::to_lowercase)
+			.collect()
+	})
+}
+
+/// Returns `true` if `domain` is a free consumer mailbox host (B2C) rather than
+/// a corporate/custom domain (B2B). Matching is case-insensitive and done on
+/// the registrable domain: a subdomain host such as `mx.gmail.com` still
+/// matches the listed `gmail.com`. The bundled list can be extended per-check —
+/// without recompiling — via [`CheckEmailInput::extra_free_domains`].
+pub fn is_free(domain: &str, input: &CheckEmailInput) -> bool {
+	let domain = domain.trim().to_lowercase();
+
+	super::domain_suffixes(&domain).any(|suffix| {
+		bundled_domains().contains(suffix)
+			|| input
+				.extra_free_domains
+				.iter()
+				.any(|d| d.trim().to_lowercase() == suffix)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::is_free;
+	use crate::util::input_output::CheckEmailInput;
+
+	#[test]
+	fn detects_bundled_free_provider() {
+		let input = CheckEmailInput::default();
+		assert!(is_free("gmail.com", &input));
+	}
+
+	#[test]
+	fn matches_on_registrable_domain_of_subdomain() {
+		let input = CheckEmailInput::default();
+		assert!(is_free("mx.gmail.com", &input));
+	}
+
+	#[test]
+	fn treats_corporate_domain_as_non_free() {
+		let input = CheckEmailInput::default();
+		assert!(!is_free("reacher.email", &input));
+	}
+
+	#[test]
+	fn honours_extra_domains_from_input() {
+		let mut input = CheckEmailInput::default();
+		input.set_extra_free_domains(vec!["example.com".into()]);
+		assert!(is_free("example.com", &input));
+		assert!(is_free("mail.example.com", &input));
+	}
+}
@@ -0,0 +1,81 @@
+// check-if-email-exists
+// Copyright (C) 2018-2022 Reacher
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published
+// by the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use async_smtp::EmailAddress;
+use reqwest::Proxy;
+
+use crate::util::input_output::CheckEmailInput;
+
+/// Gravatar keys a profile on the MD5 hash of the trimmed, lowercased email
+/// address, rendered as lowercase hex.
+fn gravatar_hash(email: &str) -> String {
+	format!("{:x}", md5::compute(email.trim().to_lowercase()))
+}
+
+/// Probe Gravatar to see whether a profile exists for `to_email`. We request
+/// the avatar with `d=404`, so Gravatar returns HTTP 200 when a profile exists
+/// and HTTP 404 otherwise. A 200 is evidence that a real person owns the
+/// address; a 404 is inconclusive.
+///
+/// The lookup reuses the same timeout and proxy configuration as the SMTP
+/// connections in this module.
+pub async fn check_gravatar(
+	to_email: &EmailAddress,
+	input: &CheckEmailInput,
+) -> Result<bool, reqwest::Error> {
+	let url = format!(
+		"https://www.gravatar.com/avatar/{}?d=404",
+		gravatar_hash(&to_email.to_string())
+	);
+
+	let mut builder = reqwest::Client::builder();
+	if let Some(timeout) = input.smtp_timeout() {
+		builder = builder.timeout(timeout);
+	}
+	if let Some(proxy) = &input.proxy {
+		let mut reqwest_proxy = Proxy::all(format!("socks5://{}:{}", proxy.host, proxy.port))?;
+		if let (Some(username), Some(password)) = (&proxy.username, &proxy.password) {
+			reqwest_proxy = reqwest_proxy.basic_auth(username, password);
+		}
+		builder = builder.proxy(reqwest_proxy);
+	}
+
+	let response = builder.build()?.get(&url).send().await?;
+
+	Ok(response.status().is_success())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::gravatar_hash;
+
+	#[test]
+	fn hashes_canonical_gravatar_example() {
+		// The reference hash from Gravatar's own documentation.
+		assert_eq!(
+			gravatar_hash("MyEmailAddress@example.com "),
+			"0bc83cb571cd1c50ba6f3e8a78ef1346"
+		);
+	}
+
+	#[test]
+	fn normalizes_case_and_whitespace() {
+		assert_eq!(
+			gravatar_hash("  Foo@Example.COM  "),
+			gravatar_hash("foo@example.com")
+		);
+	}
+}
@@ -15,11 +15,16 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 mod connect;
+mod disposable;
 mod error;
+mod free;
 mod gmail;
+mod gravatar;
 mod http_api;
 mod microsoft;
 mod parser;
+mod role;
+mod typo;
 mod yahoo;
 
 use std::default::Default;
@@ -28,12 +33,15 @@ use async_smtp::EmailAddress;
 use serde::{Deserialize, Serialize};
 use trust_dns_proto::rr::Name;
 
-use crate::{util::input_output::CheckEmailInput, LOG_TARGET};
+use crate::{util::input_output::CheckEmailInput, Reachability, LOG_TARGET};
 use connect::check_smtp_with_retry;
 pub use error::*;
+// Re-exported within the crate; `check_smtp` below is its only caller, so
+// domains that fail MX resolution get no "did you mean" correction.
+pub(crate) use typo::suggest_domain;
 
 /// Details that we gathered from connecting to this email via SMTP
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct SmtpDetails {
 	/// Are we able to connect to the SMTP server?
 	pub can_connect_smtp: bool,
@@ -45,6 +53,73 @@ pub struct SmtpDetails {
 	pub is_deliverable: bool,
 	/// Is the email blocked or disabled by the provider?
 	pub is_disabled: bool,
+	/// Is the domain a known disposable / throwaway email provider?
+	pub is_disposable: bool,
+	/// Is the local part a generic role account (e.g. `info@`, `support@`)
+	/// rather than a personal mailbox?
+	pub is_role_account: bool,
+	/// Is the domain a free consumer mailbox provider (B2C) rather than a
+	/// corporate/custom domain (B2B)?
+	pub is_free: bool,
+	/// A "did you mean" correction when the domain looks like a typo of a
+	/// popular provider (e.g. `gmial.com` → `gmail.com`).
+	pub suggested_domain: Option<String>,
+	/// Does a Gravatar profile exist for this address? Only probed when
+	/// [`CheckEmailInput::check_gravatar`] is enabled and the domain is a
+	/// catch-all; otherwise `false`.
+	pub has_gravatar: bool,
+	/// Consolidated, directly-actionable verdict derived from the raw booleans
+	/// above. Reuses the crate-level [`crate::Reachability`] so the SMTP result
+	/// and the public output share a single verdict type.
+	pub reachability: Reachability,
+}
+
+impl Default for SmtpDetails {
+	fn default() -> Self {
+		SmtpDetails {
+			can_connect_smtp: false,
+			has_full_inbox: false,
+			is_catch_all: false,
+			is_deliverable: false,
+			is_disabled: false,
+			is_disposable: false,
+			is_role_account: false,
+			is_free: false,
+			suggested_domain: None,
+			has_gravatar: false,
+			reachability: Reachability::Unknown,
+		}
+	}
+}
+
+impl From<&SmtpDetails> for Reachability {
+	fn from(details: &SmtpDetails) -> Self {
+		if !details.can_connect_smtp {
+			return Reachability::Unknown;
+		}
+		// A confirmed Gravatar profile is evidence a real person owns the
+		// address, so it lets a catch-all domain escape the `Risky` verdict.
+		let catch_all_risky = details.is_catch_all && !details.has_gravatar;
+		if catch_all_risky
+			|| details.is_disabled
+			|| details.has_full_inbox
+			|| details.is_role_account
+		{
+			return Reachability::Risky;
+		}
+		if details.is_deliverable {
+			Reachability::Safe
+		} else {
+			Reachability::Invalid
+		}
+	}
+}
+
+/// Yield `domain` and each of its parent domains, from the most specific to the
+/// least, e.g. `mx.gmail.com` → `mx.gmail.com`, `gmail.com`, `com`. Used to
+/// match a host against provider lists keyed on the registrable domain.
+fn domain_suffixes(domain: &str) -> impl Iterator<Item = &str> {
+	std::iter::successors(Some(domain), |d| d.split_once('.').map(|(_, rest)| rest))
 }
 
 /// Get all email details we can from one single `EmailAddress`, without
@@ -55,6 +130,76 @@ pub async fn check_smtp(
 	port: u16,
 	domain: &str,
 	input: &CheckEmailInput,
+) -> Result<SmtpDetails, SmtpError> {
+	// The local part is the portion of the address before the `@`. Role
+	// accounts are classified here, up-front, as it needs no network call.
+	let is_role_account = to_email
+		.to_string()
+		.rsplit_once('@')
+		.map(|(local_part, _)| role::is_role_account(local_part))
+		.unwrap_or(false);
+
+	// Classify the domain as a free consumer provider vs. a corporate one. This
+	// is a pure lookup, so it is computed up-front alongside the role check.
+	let is_free = free::is_free(domain, input);
+
+	// Disposable domains frequently accept every address, so membership is
+	// checked before any network I/O. Callers can opt into skipping the SMTP
+	// probe entirely to save a connection.
+	let is_disposable = disposable::is_disposable(domain, input);
+	if is_disposable && input.skip_smtp_on_disposable {
+		let mut details = SmtpDetails {
+			is_disposable,
+			is_role_account,
+			is_free,
+			..Default::default()
+		};
+		details.reachability = Reachability::from(&details);
+		return Ok(details);
+	}
+
+	let mut details = check_smtp_inner(to_email, host, port, domain, input).await?;
+	details.is_disposable = is_disposable;
+	details.is_role_account = is_role_account;
+	details.is_free = is_free;
+	// When we could not connect over SMTP, the domain may simply be misspelled,
+	// so offer a correction for obvious typos of popular providers. This covers
+	// domains that resolve but refuse the connection only; domains with no MX
+	// record fail DNS resolution upstream and never reach `check_smtp`, so they
+	// get no suggestion.
+	if !details.can_connect_smtp {
+		details.suggested_domain = suggest_domain(domain, input);
+	}
+	// A Gravatar profile only ever changes the verdict for a catch-all domain
+	// (see `Reachability::from`), so we only pay for the extra HTTP round-trip
+	// when the SMTP result is ambiguous — never for an address that already has
+	// a clear deliverable/undeliverable verdict.
+	if input.check_gravatar && details.is_catch_all {
+		details.has_gravatar = gravatar::check_gravatar(to_email, input)
+			.await
+			.unwrap_or_else(|err| {
+				log::debug!(
+					target: LOG_TARGET,
+					"[email={}] gravatar error: {:?}",
+					to_email,
+					err,
+				);
+				false
+			});
+	}
+	details.reachability = Reachability::from(&details);
+	Ok(details)
+}
+
+/// Run the actual SMTP probe (or provider-specific API) and return the raw
+/// [`SmtpDetails`]. [`check_smtp`] wraps this to derive the [`Reachability`]
+/// verdict.
+async fn check_smtp_inner(
+	to_email: &EmailAddress,
+	host: &Name,
+	port: u16,
+	domain: &str,
+	input: &CheckEmailInput,
 ) -> Result<SmtpDetails, SmtpError> {
 	let host_lowercase = host.to_lowercase().to_string();
 
@@ -119,7 +264,7 @@ pub async fn check_smtp(
 
 #[cfg(test)]
 mod tests {
-	use super::{check_smtp, CheckEmailInput, SmtpError};
+	use super::{check_smtp, CheckEmailInput, Reachability, SmtpDetails, SmtpError};
 	use async_smtp::EmailAddress;
 	use std::{str::FromStr, time::Duration};
 	use tokio::runtime::Runtime;
@@ -155,4 +300,77 @@ mod tests {
 			r => panic!("{:?}", r),
 		}
 	}
+
+	#[test]
+	fn reachability_is_unknown_when_cannot_connect() {
+		let details = SmtpDetails {
+			can_connect_smtp: false,
+			is_deliverable: true,
+			..Default::default()
+		};
+		assert_eq!(Reachability::from(&details), Reachability::Unknown);
+	}
+
+	#[test]
+	fn reachability_is_safe_for_deliverable_mailbox() {
+		let details = SmtpDetails {
+			can_connect_smtp: true,
+			is_deliverable: true,
+			..Default::default()
+		};
+		assert_eq!(Reachability::from(&details), Reachability::Safe);
+	}
+
+	#[test]
+	fn reachability_is_invalid_when_not_deliverable() {
+		let details = SmtpDetails {
+			can_connect_smtp: true,
+			is_deliverable: false,
+			..Default::default()
+		};
+		assert_eq!(Reachability::from(&details), Reachability::Invalid);
+	}
+
+	#[test]
+	fn reachability_is_risky_for_catch_all_disabled_full_inbox_or_role_account() {
+		for details in [
+			SmtpDetails {
+				can_connect_smtp: true,
+				is_catch_all: true,
+				is_deliverable: true,
+				..Default::default()
+			},
+			SmtpDetails {
+				can_connect_smtp: true,
+				is_disabled: true,
+				..Default::default()
+			},
+			SmtpDetails {
+				can_connect_smtp: true,
+				has_full_inbox: true,
+				..Default::default()
+			},
+			SmtpDetails {
+				can_connect_smtp: true,
+				is_role_account: true,
+				is_deliverable: true,
+				..Default::default()
+			},
+		] {
+			assert_eq!(Reachability::from(&details), Reachability::Risky);
+		}
+	}
+
+	#[test]
+	fn reachability_gravatar_rescues_catch_all() {
+		// A confirmed Gravatar profile lifts a catch-all domain out of `Risky`.
+		let details = SmtpDetails {
+			can_connect_smtp: true,
+			is_catch_all: true,
+			is_deliverable: true,
+			has_gravatar: true,
+			..Default::default()
+		};
+		assert_eq!(Reachability::from(&details), Reachability::Safe);
+	}
 }
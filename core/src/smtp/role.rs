@@ -0,0 +1,68 @@
+// check-if-email-exists
+// Copyright (C) 2018-2022 Reacher
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published
+// by the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Curated set of generic role local parts, i.e. addresses that route to a
+/// function rather than a single person. Such mailboxes are often catch-all
+/// backed and therefore deserve extra scrutiny.
+const ROLE_ACCOUNTS: &[&str] = &[
+	"abuse",
+	"admin",
+	"billing",
+	"contact",
+	"help",
+	"hostmaster",
+	"info",
+	"marketing",
+	"noreply",
+	"no-reply",
+	"postmaster",
+	"sales",
+	"security",
+	"support",
+	"webmaster",
+];
+
+/// Returns `true` if `local_part` (the portion of an address before the `@`)
+/// is a generic role account rather than a personal mailbox. Matching is
+/// case-insensitive.
+pub fn is_role_account(local_part: &str) -> bool {
+	let local_part = local_part.trim().to_lowercase();
+	ROLE_ACCOUNTS.iter().any(|role| *role == local_part)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::is_role_account;
+
+	#[test]
+	fn detects_generic_role_accounts() {
+		for local_part in ["info", "support", "admin", "no-reply"] {
+			assert!(is_role_account(local_part), "{local_part} should be a role");
+		}
+	}
+
+	#[test]
+	fn is_case_insensitive() {
+		assert!(is_role_account("Info"));
+		assert!(is_role_account("  SUPPORT  "));
+	}
+
+	#[test]
+	fn leaves_personal_mailbox_untouched() {
+		assert!(!is_role_account("john.doe"));
+		assert!(!is_role_account("jane"));
+	}
+}
@@ -0,0 +1,167 @@
+// check-if-email-exists
+// Copyright (C) 2018-2022 Reacher
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published
+// by the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::util::input_output::CheckEmailInput;
+
+/// Popular mailbox providers used to detect near-miss typos in the input
+/// domain. Overridable per-check via [`CheckEmailInput::typo_candidate_domains`].
+const POPULAR_DOMAINS: &[&str] = &[
+	"aol.com",
+	"gmail.com",
+	"gmx.net",
+	"hotmail.com",
+	"icloud.com",
+	"live.com",
+	"mail.com",
+	"msn.com",
+	"outlook.com",
+	"protonmail.com",
+	"yahoo.com",
+];
+
+/// Default maximum edit distance for which a suggestion is offered.
+const DEFAULT_MAX_DISTANCE: usize = 2;
+
+/// Damerau–Levenshtein (optimal string alignment) distance between `a` and `b`,
+/// counting insertions, deletions, substitutions and transpositions of two
+/// adjacent characters.
+fn damerau_levenshtein(a: &str, b: &str) -> usize {
+	let a: Vec<char> = a.chars().collect();
+	let b: Vec<char> = b.chars().collect();
+	let (n, m) = (a.len(), b.len());
+
+	if n == 0 {
+		return m;
+	}
+	if m == 0 {
+		return n;
+	}
+
+	let mut prev_prev = vec![0usize; m + 1];
+	let mut prev: Vec<usize> = (0..=m).collect();
+	let mut curr = vec![0usize; m + 1];
+
+	for i in 1..=n {
+		curr[0] = i;
+		for j in 1..=m {
+			let cost = usize::from(a[i - 1] != b[j - 1]);
+			curr[j] = (prev[j] + 1)
+				.min(curr[j - 1] + 1)
+				.min(prev[j - 1] + cost);
+			if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+				curr[j] = curr[j].min(prev_prev[j - 2] + 1);
+			}
+		}
+		std::mem::swap(&mut prev_prev, &mut prev);
+		std::mem::swap(&mut prev, &mut curr);
+	}
+
+	prev[m]
+}
+
+/// Offer a "did you mean" correction for `domain` when it is a near-miss of a
+/// popular provider. A suggestion is returned only when the closest candidate
+/// is within the configured threshold (default 2, minimum 1) and is *strictly*
+/// closer than every other candidate, to avoid ambiguous false positives.
+pub fn suggest_domain(domain: &str, input: &CheckEmailInput) -> Option<String> {
+	let domain = domain.trim().to_lowercase();
+	if domain.is_empty() {
+		return None;
+	}
+
+	let max_distance = input.typo_max_distance.unwrap_or(DEFAULT_MAX_DISTANCE);
+
+	let owned_candidates = &input.typo_candidate_domains;
+	let candidates: Vec<String> = if owned_candidates.is_empty() {
+		POPULAR_DOMAINS.iter().map(|d| d.to_string()).collect()
+	} else {
+		owned_candidates
+			.iter()
+			.map(|d| d.trim().to_lowercase())
+			.collect()
+	};
+
+	let mut best: Option<(usize, &String)> = None;
+	let mut second_best = usize::MAX;
+	for candidate in &candidates {
+		let distance = damerau_levenshtein(&domain, candidate);
+		match best {
+			Some((best_distance, _)) if distance < best_distance => {
+				second_best = best_distance;
+				best = Some((distance, candidate));
+			}
+			Some(_) => second_best = second_best.min(distance),
+			None => best = Some((distance, candidate)),
+		}
+	}
+
+	match best {
+		Some((distance, candidate))
+			if (1..=max_distance).contains(&distance) && distance < second_best =>
+		{
+			Some(candidate.clone())
+		}
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{damerau_levenshtein, suggest_domain};
+	use crate::util::input_output::CheckEmailInput;
+
+	#[test]
+	fn damerau_levenshtein_counts_edits() {
+		assert_eq!(damerau_levenshtein("gmail.com", "gmail.com"), 0);
+		// A single adjacent transposition (`ia` ↔ `ai`).
+		assert_eq!(damerau_levenshtein("gmial.com", "gmail.com"), 1);
+		// One substitution.
+		assert_eq!(damerau_levenshtein("gmael.com", "gmail.com"), 1);
+		// One insertion / deletion.
+		assert_eq!(damerau_levenshtein("gmai.com", "gmail.com"), 1);
+	}
+
+	#[test]
+	fn suggests_correction_for_transposition_typo() {
+		let input = CheckEmailInput::default();
+		assert_eq!(
+			suggest_domain("gmial.com", &input),
+			Some("gmail.com".to_string())
+		);
+	}
+
+	#[test]
+	fn exact_match_returns_none() {
+		let input = CheckEmailInput::default();
+		assert_eq!(suggest_domain("gmail.com", &input), None);
+	}
+
+	#[test]
+	fn ambiguous_tie_returns_none() {
+		// Equidistant (distance 1) from both candidates, so no single best.
+		let mut input = CheckEmailInput::default();
+		input.set_typo_candidate_domains(vec!["aaa.com".into(), "aab.com".into()]);
+		assert_eq!(suggest_domain("aac.com", &input), None);
+	}
+
+	#[test]
+	fn respects_max_distance() {
+		let mut input = CheckEmailInput::default();
+		input.set_typo_max_distance(Some(1));
+		// Two edits away from `gmail.com`, beyond the configured threshold.
+		assert_eq!(suggest_domain("gmiale.com", &input), None);
+	}
+}
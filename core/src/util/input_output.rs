@@ -0,0 +1,207 @@
+// check-if-email-exists
+// Copyright (C) 2018-2022 Reacher
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published
+// by the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for an outgoing SOCKS5 proxy, through which the SMTP (and
+/// Gravatar) connections are tunnelled.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckEmailInputProxy {
+	/// Host on which the proxy is listening.
+	pub host: String,
+	/// Port on which the proxy is listening.
+	pub port: u16,
+	/// Username to authenticate against the proxy, if any.
+	pub username: Option<String>,
+	/// Password to authenticate against the proxy, if any.
+	pub password: Option<String>,
+}
+
+/// All the options that control how a single email address is verified.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckEmailInput {
+	/// The email to validate.
+	pub to_email: String,
+	/// Email to use in the `MAIL FROM:` SMTP command.
+	pub from_email: String,
+	/// Name to use in the `EHLO:` SMTP command.
+	pub hello_name: String,
+	/// Proxy through which to run all the verification connections.
+	pub proxy: Option<CheckEmailInputProxy>,
+	/// SMTP port to use to connect to the mail server.
+	pub smtp_port: u16,
+	/// Timeout for each SMTP connection.
+	pub smtp_timeout: Option<Duration>,
+	/// For Yahoo addresses, use the headless-less API instead of connecting
+	/// directly to their SMTP servers.
+	pub yahoo_use_api: bool,
+	/// For Gmail addresses, use the Gmail SMTP API instead of connecting
+	/// directly to their SMTP servers.
+	pub gmail_use_api: bool,
+	/// For Microsoft 365 addresses, use OneDrive's API instead of connecting
+	/// directly to their SMTP servers.
+	pub microsoft365_use_api: bool,
+	/// For Hotmail/Outlook addresses, use a headless navigator connecting to
+	/// the password recovery page to check whether the email exists.
+	#[cfg(feature = "headless")]
+	pub hotmail_use_headless: Option<String>,
+	/// Domains for which we never attempt an SMTP connection.
+	pub skipped_domains: Vec<String>,
+	/// Additional disposable-provider domains to treat as disposable, on top of
+	/// the list bundled with the crate. Matched on the registrable domain,
+	/// case-insensitively.
+	pub extra_disposable_domains: Vec<String>,
+	/// Skip the SMTP probe entirely — returning early with `is_disposable` set —
+	/// when the domain is a known disposable provider.
+	pub skip_smtp_on_disposable: bool,
+	/// Additional free-provider domains to treat as free consumer mailboxes, on
+	/// top of the list bundled with the crate. Matched on the registrable
+	/// domain, case-insensitively.
+	pub extra_free_domains: Vec<String>,
+	/// Maximum Damerau–Levenshtein distance for which a "did you mean" domain
+	/// suggestion is offered. `None` falls back to the built-in default.
+	pub typo_max_distance: Option<usize>,
+	/// Candidate provider domains against which the input domain is compared
+	/// when looking for typos. Empty falls back to the built-in popular-provider
+	/// list.
+	pub typo_candidate_domains: Vec<String>,
+	/// Probe Gravatar for a matching profile as an additional reachability
+	/// signal. Off by default, as it adds an extra HTTP round-trip.
+	pub check_gravatar: bool,
+	/// Number of times to retry an SMTP connection on a transient failure.
+	pub retries: usize,
+}
+
+impl Default for CheckEmailInput {
+	fn default() -> Self {
+		CheckEmailInput {
+			to_email: "".into(),
+			from_email: "user@example.org".into(),
+			hello_name: "localhost".into(),
+			proxy: None,
+			smtp_port: 25,
+			smtp_timeout: None,
+			yahoo_use_api: true,
+			gmail_use_api: true,
+			microsoft365_use_api: true,
+			#[cfg(feature = "headless")]
+			hotmail_use_headless: None,
+			skipped_domains: vec![".mail.icloud.com.".into()],
+			extra_disposable_domains: vec![],
+			skip_smtp_on_disposable: false,
+			extra_free_domains: vec![],
+			typo_max_distance: None,
+			typo_candidate_domains: vec![],
+			check_gravatar: false,
+			retries: 2,
+		}
+	}
+}
+
+impl CheckEmailInput {
+	/// Create a new `CheckEmailInput` for the given email address, with all
+	/// other options left at their defaults.
+	pub fn new(to_email: String) -> CheckEmailInput {
+		CheckEmailInput {
+			to_email,
+			..Default::default()
+		}
+	}
+
+	/// Set the email to use in the `MAIL FROM:` SMTP command.
+	pub fn set_from_email(&mut self, from_email: String) -> &mut CheckEmailInput {
+		self.from_email = from_email;
+		self
+	}
+
+	/// Set the name to use in the `EHLO:` SMTP command.
+	pub fn set_hello_name(&mut self, hello_name: String) -> &mut CheckEmailInput {
+		self.hello_name = hello_name;
+		self
+	}
+
+	/// Set the proxy through which to run all verification connections.
+	pub fn set_proxy(&mut self, proxy: CheckEmailInputProxy) -> &mut CheckEmailInput {
+		self.proxy = Some(proxy);
+		self
+	}
+
+	/// Set the timeout for each SMTP connection.
+	pub fn set_smtp_timeout(&mut self, timeout: Option<Duration>) -> &mut CheckEmailInput {
+		self.smtp_timeout = timeout;
+		self
+	}
+
+	/// Timeout for each SMTP connection, if any.
+	pub fn smtp_timeout(&self) -> Option<Duration> {
+		self.smtp_timeout
+	}
+
+	/// Add disposable-provider domains to treat as disposable, on top of the
+	/// bundled list.
+	pub fn set_extra_disposable_domains(
+		&mut self,
+		domains: Vec<String>,
+	) -> &mut CheckEmailInput {
+		self.extra_disposable_domains = domains;
+		self
+	}
+
+	/// Skip the SMTP probe when the domain is a known disposable provider.
+	pub fn set_skip_smtp_on_disposable(&mut self, skip: bool) -> &mut CheckEmailInput {
+		self.skip_smtp_on_disposable = skip;
+		self
+	}
+
+	/// Add free-provider domains to treat as free consumer mailboxes, on top of
+	/// the bundled list.
+	pub fn set_extra_free_domains(&mut self, domains: Vec<String>) -> &mut CheckEmailInput {
+		self.extra_free_domains = domains;
+		self
+	}
+
+	/// Set the maximum edit distance for which a domain typo suggestion is
+	/// offered.
+	pub fn set_typo_max_distance(&mut self, distance: Option<usize>) -> &mut CheckEmailInput {
+		self.typo_max_distance = distance;
+		self
+	}
+
+	/// Set the candidate provider domains used when looking for typos.
+	pub fn set_typo_candidate_domains(
+		&mut self,
+		domains: Vec<String>,
+	) -> &mut CheckEmailInput {
+		self.typo_candidate_domains = domains;
+		self
+	}
+
+	/// Enable or disable the Gravatar existence probe.
+	pub fn set_check_gravatar(&mut self, check_gravatar: bool) -> &mut CheckEmailInput {
+		self.check_gravatar = check_gravatar;
+		self
+	}
+
+	/// Set the number of SMTP connection retries.
+	pub fn set_retries(&mut self, retries: usize) -> &mut CheckEmailInput {
+		self.retries = retries;
+		self
+	}
+}